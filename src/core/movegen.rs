@@ -0,0 +1,41 @@
+//! Move generation over a [`Board`](super::board::Board).
+
+use super::board::{Process, Validate};
+
+/// Represents a board which can enumerate its own moves.
+///
+/// For a notion of legality see [`Validate`]; `GenerateMoves` builds on top
+/// of it by actually producing the moves a `Validate` implementation would
+/// accept, rather than merely checking a candidate against them.
+pub trait GenerateMoves: Validate {
+    /// Returns every legal move available to the side to move.
+    fn legal_moves(&self) -> impl Iterator<Item = Self::LegalMove>;
+
+    /// Returns every pseudo-legal move available to the side to move, i.e.
+    /// ignoring whether making the move would leave its own king in check.
+    ///
+    /// This is cheaper than [`GenerateMoves::legal_moves`] and is mostly
+    /// useful as a building block for it.
+    fn pseudo_legal_moves(&self) -> impl Iterator<Item = Self::Move>;
+}
+
+/// Counts the number of leaf nodes reachable from `board` after exactly
+/// `depth` plies, by recursively generating and processing legal moves.
+///
+/// This is the standard correctness benchmark for a move generator: known
+/// positions have well-documented node counts at each depth, so a generator
+/// (and the [`Process`] implementation it depends on) can be checked against
+/// them directly.
+pub fn perft<B>(board: &B, depth: usize) -> u64
+where
+    B: GenerateMoves + Process,
+{
+    if depth == 0 {
+        return 1;
+    }
+
+    board
+        .legal_moves()
+        .map(|mv| perft(&board.process(mv), depth - 1))
+        .sum()
+}
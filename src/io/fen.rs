@@ -0,0 +1,363 @@
+//! Forsyth–Edwards Notation (FEN), the standard way to describe a single
+//! chess position as a single line of text.
+//!
+//! A FEN record is six space-separated fields: piece placement, side to
+//! move, castling availability, the en passant target square, the halfmove
+//! clock, and the fullmove number. The first four are exactly what a
+//! [`StandardBoard`] needs to exist; the last two are bookkeeping that
+//! nothing else in this crate consults, so they travel alongside the board
+//! in [`Fen`] rather than living on it.
+
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::core::board::Board;
+use crate::standard::board::StandardBoard;
+use crate::standard::index::StandardIndex;
+use crate::standard::piece::{Color, StandardPiece, StandardPieceKind};
+use crate::standard::r#move::CastleSide;
+
+/// The error returned when attempting to parse an invalid FEN record.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    /// Returned if the record does not split into exactly 6 fields.
+    #[error("expected 6 space-separated fields; got {0}")]
+    InvalidFieldCount(usize),
+    /// Returned if the piece placement field does not have 8 ranks.
+    #[error("expected 8 '/'-separated ranks; got {0}")]
+    InvalidRankCount(usize),
+    /// Returned if a rank does not describe exactly 8 files.
+    #[error("rank {0:?} does not describe exactly 8 files")]
+    InvalidRankLength(String),
+    /// Returned if a piece placement character is not recognised.
+    #[error("unrecognised piece character {0:?}")]
+    InvalidPieceChar(char),
+    /// Returned if the side to move field is not `w` or `b`.
+    #[error("expected 'w' or 'b' for the side to move; got {0:?}")]
+    InvalidSideToMove(String),
+    /// Returned if the castling availability field is malformed.
+    #[error("expected a subset of \"KQkq\" or \"-\" for castling availability; got {0:?}")]
+    InvalidCastlingAvailability(String),
+    /// Returned if the en passant target square field is malformed.
+    #[error("expected a valid en passant target square or \"-\"; got {0:?}")]
+    InvalidEnPassantTarget(String),
+    /// Returned if the halfmove clock is not a non-negative integer.
+    #[error("expected a non-negative integer for the halfmove clock; got {0:?}")]
+    InvalidHalfmoveClock(String),
+    /// Returned if the fullmove number is not a positive integer.
+    #[error("expected a positive integer for the fullmove number; got {0:?}")]
+    InvalidFullmoveNumber(String),
+}
+
+/// A fully parsed FEN record.
+///
+/// Parsing is provided via the `TryFrom<&str>` impl, and serialization back
+/// to the canonical FEN string via `Display`/[`Fen::to_fen`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fen {
+    /// The position described by the record.
+    pub board: StandardBoard,
+    /// The number of halfmoves since the last capture or pawn advance, used
+    /// to enforce the fifty-move rule.
+    pub halfmove_clock: u32,
+    /// The number of the full move about to be played, starting at 1 and
+    /// incrementing after Black moves.
+    pub fullmove_number: u32,
+}
+
+impl<'a> TryFrom<&'a str> for Fen {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let fields: Vec<&str> = value.split_whitespace().collect();
+        let [placement, side_to_move, castling, en_passant, halfmove_clock, fullmove_number] =
+            fields
+                .as_slice()
+                .try_into()
+                .map_err(|_| ParseError::InvalidFieldCount(fields.len()))?;
+
+        let pieces = parse_placement(placement)?;
+        let side_to_move = parse_side_to_move(side_to_move)?;
+        let [white_kingside, white_queenside, black_kingside, black_queenside] =
+            parse_castling_rights(castling)?;
+        let en_passant_target = parse_en_passant_target(en_passant)?;
+        let halfmove_clock = halfmove_clock
+            .parse()
+            .map_err(|_| ParseError::InvalidHalfmoveClock(halfmove_clock.to_owned()))?;
+        let fullmove_number: u32 = fullmove_number
+            .parse()
+            .map_err(|_| ParseError::InvalidFullmoveNumber(fullmove_number.to_owned()))?;
+        if fullmove_number == 0 {
+            return Err(ParseError::InvalidFullmoveNumber(fullmove_number.to_string()));
+        }
+
+        let board = StandardBoard::new(
+            pieces,
+            side_to_move,
+            white_kingside,
+            white_queenside,
+            black_kingside,
+            black_queenside,
+            en_passant_target,
+        );
+
+        Ok(Self {
+            board,
+            halfmove_clock,
+            fullmove_number,
+        })
+    }
+}
+
+impl Fen {
+    /// Renders this record as a canonical FEN string.
+    ///
+    /// This is equivalent to `self.to_string()`, provided via `Display`.
+    pub fn to_fen(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Fen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_placement(f, &self.board)?;
+        write!(f, " ")?;
+        write!(
+            f,
+            "{}",
+            match self.board.side_to_move() {
+                Color::White => 'w',
+                Color::Black => 'b',
+            }
+        )?;
+        write!(f, " ")?;
+        write_castling_rights(f, &self.board)?;
+        write!(f, " ")?;
+        match self.board.en_passant_target() {
+            Some(square) => write!(f, "{}{}", (b'a' + square.file()) as char, (b'1' + square.rank()) as char)?,
+            None => write!(f, "-")?,
+        }
+        write!(f, " {} {}", self.halfmove_clock, self.fullmove_number)
+    }
+}
+
+/// Parses the piece placement field into a list of occupied squares.
+fn parse_placement(placement: &str) -> Result<Vec<(StandardIndex, StandardPiece)>, ParseError> {
+    let ranks: Vec<&str> = placement.split('/').collect();
+    if ranks.len() != 8 {
+        return Err(ParseError::InvalidRankCount(ranks.len()));
+    }
+
+    let mut pieces = Vec::new();
+
+    for (i, rank_str) in ranks.iter().enumerate() {
+        let rank = 7 - i as u8;
+        let mut file = 0u8;
+
+        for ch in rank_str.chars() {
+            if let Some(skip) = ch.to_digit(10) {
+                file += skip as u8;
+                if file > 8 {
+                    return Err(ParseError::InvalidRankLength((*rank_str).to_owned()));
+                }
+                continue;
+            }
+
+            let piece = piece_from_char(ch).ok_or(ParseError::InvalidPieceChar(ch))?;
+            let square = StandardIndex::from_file_rank(file, rank)
+                .ok_or_else(|| ParseError::InvalidRankLength((*rank_str).to_owned()))?;
+            pieces.push((square, piece));
+            file += 1;
+        }
+
+        if file != 8 {
+            return Err(ParseError::InvalidRankLength((*rank_str).to_owned()));
+        }
+    }
+
+    Ok(pieces)
+}
+
+/// Parses a single FEN piece placement character, e.g. `P` or `n`.
+fn piece_from_char(ch: char) -> Option<StandardPiece> {
+    let color = if ch.is_ascii_uppercase() {
+        Color::White
+    } else {
+        Color::Black
+    };
+    let kind = match ch.to_ascii_uppercase() {
+        'P' => StandardPieceKind::Pawn,
+        'N' => StandardPieceKind::Knight,
+        'B' => StandardPieceKind::Bishop,
+        'R' => StandardPieceKind::Rook,
+        'Q' => StandardPieceKind::Queen,
+        'K' => StandardPieceKind::King,
+        _ => return None,
+    };
+
+    Some(StandardPiece::new(kind, color))
+}
+
+/// Parses the side to move field.
+fn parse_side_to_move(field: &str) -> Result<Color, ParseError> {
+    match field {
+        "w" => Ok(Color::White),
+        "b" => Ok(Color::Black),
+        _ => Err(ParseError::InvalidSideToMove(field.to_owned())),
+    }
+}
+
+/// Parses the castling availability field into
+/// `[white_kingside, white_queenside, black_kingside, black_queenside]`.
+fn parse_castling_rights(field: &str) -> Result<[bool; 4], ParseError> {
+    if field == "-" {
+        return Ok([false; 4]);
+    }
+
+    if field.is_empty() || field.len() > 4 || !field.chars().all(|c| "KQkq".contains(c)) {
+        return Err(ParseError::InvalidCastlingAvailability(field.to_owned()));
+    }
+
+    Ok([
+        field.contains('K'),
+        field.contains('Q'),
+        field.contains('k'),
+        field.contains('q'),
+    ])
+}
+
+/// Parses the en passant target square field.
+fn parse_en_passant_target(field: &str) -> Result<Option<StandardIndex>, ParseError> {
+    if field == "-" {
+        return Ok(None);
+    }
+
+    let mut chars = field.chars();
+    let (Some(file), Some(rank), None) = (chars.next(), chars.next(), chars.next()) else {
+        return Err(ParseError::InvalidEnPassantTarget(field.to_owned()));
+    };
+
+    StandardIndex::try_from((file, rank))
+        .map(Some)
+        .map_err(|_| ParseError::InvalidEnPassantTarget(field.to_owned()))
+}
+
+/// Writes the piece placement field for `board`.
+fn write_placement(f: &mut fmt::Formatter<'_>, board: &StandardBoard) -> fmt::Result {
+    for rank in (0..8).rev() {
+        let mut empty_run = 0u8;
+
+        for file in 0..8 {
+            let square = StandardIndex::from_file_rank(file, rank)
+                .expect("every (file, rank) pair in 0..8 is a valid square");
+
+            match board.get_piece_at(square) {
+                Some(piece) => {
+                    if empty_run > 0 {
+                        write!(f, "{empty_run}")?;
+                        empty_run = 0;
+                    }
+                    write!(f, "{}", piece_to_char(piece))?;
+                }
+                None => empty_run += 1,
+            }
+        }
+
+        if empty_run > 0 {
+            write!(f, "{empty_run}")?;
+        }
+        if rank > 0 {
+            write!(f, "/")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the castling availability field for `board`.
+fn write_castling_rights(f: &mut fmt::Formatter<'_>, board: &StandardBoard) -> fmt::Result {
+    let mut any = false;
+    if board.can_castle(Color::White, CastleSide::KingSide) {
+        write!(f, "K")?;
+        any = true;
+    }
+    if board.can_castle(Color::White, CastleSide::QueenSide) {
+        write!(f, "Q")?;
+        any = true;
+    }
+    if board.can_castle(Color::Black, CastleSide::KingSide) {
+        write!(f, "k")?;
+        any = true;
+    }
+    if board.can_castle(Color::Black, CastleSide::QueenSide) {
+        write!(f, "q")?;
+        any = true;
+    }
+    if !any {
+        write!(f, "-")?;
+    }
+
+    Ok(())
+}
+
+/// Returns the FEN piece placement character for `piece`.
+fn piece_to_char(piece: &StandardPiece) -> char {
+    let letter = match piece.kind() {
+        StandardPieceKind::Pawn => 'p',
+        StandardPieceKind::Knight => 'n',
+        StandardPieceKind::Bishop => 'b',
+        StandardPieceKind::Rook => 'r',
+        StandardPieceKind::Queen => 'q',
+        StandardPieceKind::King => 'k',
+    };
+
+    match piece.color() {
+        Color::White => letter.to_ascii_uppercase(),
+        Color::Black => letter,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_startpos() {
+        let record = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let fen = Fen::try_from(record).unwrap();
+        assert_eq!(fen.to_fen(), record);
+    }
+
+    #[test]
+    fn round_trips_kiwipete() {
+        let record = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let fen = Fen::try_from(record).unwrap();
+        assert_eq!(fen.to_fen(), record);
+    }
+
+    #[test]
+    fn round_trips_position_with_en_passant_target_and_partial_castling_rights() {
+        let record = "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR w Kq d6 0 3";
+        let fen = Fen::try_from(record).unwrap();
+        assert_eq!(fen.to_fen(), record);
+    }
+
+    #[test]
+    fn rejects_wrong_rank_count() {
+        let record = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1";
+        Fen::try_from(record).expect_err("should fail with only 7 ranks");
+    }
+
+    #[test]
+    fn rejects_invalid_side_to_move() {
+        let record = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1";
+        Fen::try_from(record).expect_err("should fail with an invalid side to move");
+    }
+
+    #[test]
+    fn rejects_invalid_field_count() {
+        Fen::try_from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -")
+            .expect_err("should fail with too few fields");
+    }
+}
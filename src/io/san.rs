@@ -22,7 +22,14 @@ use nom::{
 };
 use thiserror::Error;
 
-use crate::standard::piece::StandardPieceKind;
+use crate::core::board::{Board, Process, Validate};
+use crate::core::movegen::GenerateMoves;
+use crate::standard::board::StandardBoard;
+use crate::standard::index::StandardIndex;
+use crate::standard::piece::{Color, StandardPieceKind};
+use crate::standard::r#move::{
+    CastleSide, IllegalStandardMoveError, LegalStandardMove, MoveKind, StandardMove,
+};
 
 /// The error returned when attempting to
 /// parse an invalid SAN literal.
@@ -101,14 +108,519 @@ impl<'a> TryFrom<&'a str> for San {
     }
 }
 
+/// The error returned when a parsed [`San`] cannot be resolved against a board.
+#[derive(Debug, Error)]
+pub enum SanResolutionError {
+    /// Returned when no piece of the named kind can reach the target square.
+    #[error("no {0:?} can reach the target square")]
+    NoSourceFound(StandardPieceKind),
+    /// Returned when more than one piece of the named kind can reach the
+    /// target square and the disambiguation field does not narrow it down
+    /// to a single candidate.
+    #[error("{0} distinct {1:?} pieces can reach the target square")]
+    AmbiguousSource(usize, StandardPieceKind),
+    /// Returned when no pawn can make the move described by the literal.
+    #[error("no pawn can make the move described by this SAN literal")]
+    NoPawnSourceFound,
+    /// Returned when more than one pawn can make the move described by the literal.
+    #[error("{0} distinct pawns can make the move described by this SAN literal")]
+    AmbiguousPawnSource(usize),
+    /// Returned when a file/rank pair parsed out of the literal is not a real square.
+    #[error("{0}{1} is not a valid square")]
+    InvalidTargetSquare(char, char),
+    /// Returned when the resolved candidate move turns out to be illegal.
+    #[error(transparent)]
+    Illegal(#[from] IllegalStandardMoveError),
+}
+
+impl San {
+    /// Resolves this `San` into a candidate [`StandardMove`] by consulting
+    /// `board` for the piece placement needed to disambiguate which piece is
+    /// moving. The returned move is not yet known to be legal; see
+    /// [`San::into_legal_move`] for that.
+    pub fn into_move(self, board: &StandardBoard) -> Result<StandardMove, SanResolutionError> {
+        match self.data {
+            SanData::CastleMove(side) => Ok(resolve_castle(board, &side)),
+            SanData::NormalMove(mv) => resolve_normal_move(board, mv),
+            SanData::PawnMove(mv) => resolve_pawn_move(board, mv),
+            SanData::AbbreviatedPawnMove(mv) => resolve_abbreviated_pawn_move(board, mv),
+        }
+    }
+
+    /// Resolves this `San` into a [`LegalStandardMove`] by first resolving
+    /// it into a candidate move via [`San::into_move`], then validating that
+    /// candidate against `board`.
+    ///
+    /// Castling candidates are checked against [`GenerateMoves::legal_moves`]
+    /// rather than [`Validate::validate`] directly, since `validate` only
+    /// rejects moves that leave the king in check in the *resulting*
+    /// position — it has no way to reject castling out of or through check.
+    pub fn into_legal_move(
+        self,
+        board: &StandardBoard,
+    ) -> Result<LegalStandardMove, SanResolutionError> {
+        let candidate = self.into_move(board)?;
+
+        if let MoveKind::Castle { side } = candidate.kind() {
+            return board
+                .legal_moves()
+                .find(|mv| mv.kind() == MoveKind::Castle { side })
+                .ok_or(SanResolutionError::Illegal(IllegalStandardMoveError::Check(candidate)));
+        }
+
+        Ok(board.validate(candidate)?)
+    }
+}
+
+/// Renders a legal move as a FIDE appendix-C standard algebraic notation
+/// literal: a piece letter for non-pawns, minimal disambiguation, `x` on
+/// captures, `=` promotions, `O-O`/`O-O-O` castling, and a trailing `+` or
+/// `#` if the move gives check or checkmate.
+pub trait ToSan: Process {
+    /// Renders `mv`, which must be legal against `self`, as a SAN literal.
+    fn to_san(&self, mv: Self::LegalMove) -> String;
+}
+
+impl ToSan for StandardBoard {
+    fn to_san(&self, mv: LegalStandardMove) -> String {
+        let source = mv.source();
+        let target = mv.target();
+        let piece = self
+            .get_piece_at(source)
+            .expect("a legal move always departs from an occupied square");
+        let is_capture = matches!(
+            mv.kind(),
+            MoveKind::Capture | MoveKind::EnPassant | MoveKind::Promotion { is_capture: true, .. }
+        );
+
+        let mut san = String::new();
+
+        if let MoveKind::Castle { side } = mv.kind() {
+            san.push_str(match side {
+                CastleSide::KingSide => "O-O",
+                CastleSide::QueenSide => "O-O-O",
+            });
+        } else if piece.kind() == StandardPieceKind::Pawn {
+            if is_capture {
+                san.push((b'a' + source.file()) as char);
+                san.push('x');
+            }
+            san.push((b'a' + target.file()) as char);
+            san.push((b'1' + target.rank()) as char);
+            if let MoveKind::Promotion { piece, .. } = mv.kind() {
+                san.push('=');
+                san.push(piece_letter(piece));
+            }
+        } else {
+            san.push(piece_letter(piece.kind()));
+            san.push_str(&disambiguation(self, piece.kind(), source, target));
+            if is_capture {
+                san.push('x');
+            }
+            san.push((b'a' + target.file()) as char);
+            san.push((b'1' + target.rank()) as char);
+        }
+
+        let resulting_board = self.process(mv);
+        let opponent = piece.color().opposite();
+        if is_in_check(&resulting_board, opponent) {
+            san.push(if has_legal_move(&resulting_board) {
+                '+'
+            } else {
+                '#'
+            });
+        }
+
+        san
+    }
+}
+
+/// Returns the minimal disambiguation field needed to distinguish the piece
+/// on `source` from any other friendly piece of the same kind that could
+/// also *legally* reach `target`, e.g. excluding a pseudo-legal attacker
+/// that is pinned to its own king.
+fn disambiguation(
+    board: &StandardBoard,
+    kind: StandardPieceKind,
+    source: StandardIndex,
+    target: StandardIndex,
+) -> String {
+    let Some(color) = board.get_piece_at(source).map(|p| p.color()) else {
+        return String::new();
+    };
+
+    let is_capture = board.get_piece_at(target).is_some();
+    let others: Vec<StandardIndex> = StandardIndex::iter()
+        .filter(|&sq| sq != source)
+        .filter(|&sq| {
+            board
+                .get_piece_at(sq)
+                .is_some_and(|p| p.kind() == kind && p.color() == color)
+                && attacks(board, sq, kind, target)
+                && board
+                    .validate(if is_capture {
+                        StandardMove::capture(sq, target)
+                    } else {
+                        StandardMove::quiet(sq, target)
+                    })
+                    .is_ok()
+        })
+        .collect();
+
+    if others.is_empty() {
+        String::new()
+    } else if others.iter().all(|sq| sq.file() != source.file()) {
+        String::from((b'a' + source.file()) as char)
+    } else if others.iter().all(|sq| sq.rank() != source.rank()) {
+        String::from((b'1' + source.rank()) as char)
+    } else {
+        format!(
+            "{}{}",
+            (b'a' + source.file()) as char,
+            (b'1' + source.rank()) as char
+        )
+    }
+}
+
+/// Returns the SAN letter for a non-pawn piece kind.
+fn piece_letter(kind: StandardPieceKind) -> char {
+    match kind {
+        StandardPieceKind::King => 'K',
+        StandardPieceKind::Queen => 'Q',
+        StandardPieceKind::Rook => 'R',
+        StandardPieceKind::Bishop => 'B',
+        StandardPieceKind::Knight => 'N',
+        StandardPieceKind::Pawn => unreachable!("pawns have no SAN piece letter"),
+    }
+}
+
+/// Returns true if the king of `color` is attacked in the given position.
+fn is_in_check(board: &StandardBoard, color: Color) -> bool {
+    let Some(king_square) = StandardIndex::iter().find(|&sq| {
+        board
+            .get_piece_at(sq)
+            .is_some_and(|p| p.kind() == StandardPieceKind::King && p.color() == color)
+    }) else {
+        return false;
+    };
+
+    let enemy = color.opposite();
+    StandardIndex::iter().any(|sq| {
+        board.get_piece_at(sq).is_some_and(|piece| {
+            piece.color() == enemy
+                && (attacks(board, sq, piece.kind(), king_square)
+                    || (piece.kind() == StandardPieceKind::Pawn
+                        && pawn_attacks(sq, enemy, king_square)))
+        })
+    })
+}
+
+/// Returns true if the side to move in `board` has at least one legal move.
+fn has_legal_move(board: &StandardBoard) -> bool {
+    board.legal_moves().next().is_some()
+}
+
+/// Returns true if a pawn of `color` standing on `from` attacks `to`.
+fn pawn_attacks(from: StandardIndex, color: Color, to: StandardIndex) -> bool {
+    let direction: i8 = match color {
+        Color::White => 1,
+        Color::Black => -1,
+    };
+    let df = to.file() as i8 - from.file() as i8;
+    let dr = to.rank() as i8 - from.rank() as i8;
+
+    df.abs() == 1 && dr == direction
+}
+
+/// Resolves a castling literal against the side to move's home rank.
+fn resolve_castle(board: &StandardBoard, side: &CastleMove) -> StandardMove {
+    let rank = match board.side_to_move() {
+        Color::White => 0,
+        Color::Black => 7,
+    };
+    let source =
+        StandardIndex::from_file_rank(4, rank).expect("the e-file is always on the board");
+    let target_file = match side {
+        CastleMove::KingSide => 6,
+        CastleMove::QueenSide => 2,
+    };
+    let target = StandardIndex::from_file_rank(target_file, rank)
+        .expect("the g- and c-files are always on the board");
+    let castle_side = match side {
+        CastleMove::KingSide => CastleSide::KingSide,
+        CastleMove::QueenSide => CastleSide::QueenSide,
+    };
+
+    StandardMove::castle(source, target, castle_side)
+}
+
+/// Resolves a non-pawn move by scanning the board for pieces of the named
+/// kind that can *legally* reach the target square, then narrowing by the
+/// disambiguation field. Filtering by legality (rather than just pseudo-legal
+/// `attacks`) excludes e.g. a pinned piece that pseudo-legally attacks the
+/// target but can't actually move there.
+fn resolve_normal_move(
+    board: &StandardBoard,
+    mv: NormalMove,
+) -> Result<StandardMove, SanResolutionError> {
+    let target = square_from_chars(mv.target)
+        .ok_or(SanResolutionError::InvalidTargetSquare(mv.target.0, mv.target.1))?;
+    let color = board.side_to_move();
+    let probe = if mv.is_capture {
+        StandardMove::capture
+    } else {
+        StandardMove::quiet
+    };
+
+    let candidates: Vec<StandardIndex> = StandardIndex::iter()
+        .filter(|&source| {
+            board
+                .get_piece_at(source)
+                .is_some_and(|piece| piece.kind() == mv.piece && piece.color() == color)
+                && attacks(board, source, mv.piece, target)
+                && board.validate(probe(source, target)).is_ok()
+        })
+        .filter(|&source| matches_disambiguation(source, mv.disambiguation_field.as_ref()))
+        .collect();
+
+    match candidates.as_slice() {
+        [] => Err(SanResolutionError::NoSourceFound(mv.piece)),
+        [source] => Ok(if mv.is_capture {
+            StandardMove::capture(*source, target)
+        } else {
+            StandardMove::quiet(*source, target)
+        }),
+        _ => Err(SanResolutionError::AmbiguousSource(candidates.len(), mv.piece)),
+    }
+}
+
+/// Resolves a pawn move by inferring the source file (from the target file,
+/// or from the capture file if one is given) and checking the one or two
+/// ranks behind the target for a friendly pawn.
+fn resolve_pawn_move(board: &StandardBoard, mv: PawnMove) -> Result<StandardMove, SanResolutionError> {
+    let target = square_from_chars(mv.target)
+        .ok_or(SanResolutionError::InvalidTargetSquare(mv.target.0, mv.target.1))?;
+    let color = board.side_to_move();
+    let direction: i8 = match color {
+        Color::White => -1,
+        Color::Black => 1,
+    };
+    let source_file = match mv.capture_file {
+        Some(file) => file_to_index(file),
+        None => target.file(),
+    };
+
+    let mut sources = Vec::new();
+
+    if let Some(source) = pawn_source_at(board, color, source_file, target, direction, 1) {
+        sources.push((source, false));
+    }
+
+    if !mv.is_capture {
+        if let Some(source) = pawn_source_at(board, color, source_file, target, direction, 2) {
+            let mid_rank = (target.rank() as i8 + direction) as u8;
+            let mid = StandardIndex::from_file_rank(source_file, mid_rank)
+                .expect("the midpoint of a double pawn push is always on the board");
+            if board.get_piece_at(mid).is_none() {
+                sources.push((source, true));
+            }
+        }
+    }
+
+    match sources.as_slice() {
+        [] => Err(SanResolutionError::NoPawnSourceFound),
+        [(source, is_double_push)] => Ok(build_pawn_move(
+            *source,
+            target,
+            mv.is_capture,
+            *is_double_push,
+            board.en_passant_target() == Some(target),
+            mv.promotion_piece,
+        )),
+        _ => Err(SanResolutionError::AmbiguousPawnSource(sources.len())),
+    }
+}
+
+/// Builds the appropriately-kinded [`StandardMove`] for a resolved pawn move.
+fn build_pawn_move(
+    source: StandardIndex,
+    target: StandardIndex,
+    is_capture: bool,
+    is_double_push: bool,
+    is_en_passant: bool,
+    promotion_piece: Option<StandardPieceKind>,
+) -> StandardMove {
+    if let Some(piece) = promotion_piece {
+        StandardMove::promotion(source, target, piece, is_capture)
+    } else if is_capture && is_en_passant {
+        StandardMove::en_passant(source, target)
+    } else if is_double_push {
+        StandardMove::double_pawn_push(source, target)
+    } else if is_capture {
+        StandardMove::capture(source, target)
+    } else {
+        StandardMove::quiet(source, target)
+    }
+}
+
+/// Resolves an abbreviated pawn move (e.g. `ed`) by scanning every rank for a
+/// capturable (or en passant capturable) piece on the target file, reachable
+/// diagonally from a friendly pawn on the source file.
+fn resolve_abbreviated_pawn_move(
+    board: &StandardBoard,
+    mv: AbbreviatedPawnMove,
+) -> Result<StandardMove, SanResolutionError> {
+    let color = board.side_to_move();
+    let direction: i8 = match color {
+        Color::White => -1,
+        Color::Black => 1,
+    };
+    let source_file = file_to_index(mv.source_rank);
+    let target_file = file_to_index(mv.target_rank);
+    if source_file.abs_diff(target_file) != 1 {
+        return Err(SanResolutionError::NoPawnSourceFound);
+    }
+
+    let mut candidates = Vec::new();
+
+    for rank in 0u8..8 {
+        let Some(target) = StandardIndex::from_file_rank(target_file, rank) else {
+            continue;
+        };
+        let Ok(source_rank) = u8::try_from(rank as i8 + direction) else {
+            continue;
+        };
+        let Some(source) = StandardIndex::from_file_rank(source_file, source_rank) else {
+            continue;
+        };
+
+        let is_capturable = board.get_piece_at(target).is_some_and(|p| p.color() != color);
+        let is_en_passant = board.en_passant_target() == Some(target);
+        if !is_capturable && !is_en_passant {
+            continue;
+        }
+
+        if board
+            .get_piece_at(source)
+            .is_some_and(|p| p.kind() == StandardPieceKind::Pawn && p.color() == color)
+        {
+            candidates.push((source, target, is_en_passant));
+        }
+    }
+
+    match candidates.as_slice() {
+        [] => Err(SanResolutionError::NoPawnSourceFound),
+        [(source, target, is_en_passant)] => Ok(build_pawn_move(
+            *source,
+            *target,
+            true,
+            false,
+            *is_en_passant,
+            mv.promotion_piece,
+        )),
+        _ => Err(SanResolutionError::AmbiguousPawnSource(candidates.len())),
+    }
+}
+
+/// Returns the square a friendly pawn would need to occupy to reach `target`
+/// after moving `steps` ranks in the direction it advances, if such a pawn
+/// is actually there.
+fn pawn_source_at(
+    board: &StandardBoard,
+    color: Color,
+    file: u8,
+    target: StandardIndex,
+    direction: i8,
+    steps: i8,
+) -> Option<StandardIndex> {
+    let rank = u8::try_from(target.rank() as i8 + direction * steps).ok()?;
+    let source = StandardIndex::from_file_rank(file, rank)?;
+    let piece = board.get_piece_at(source)?;
+    (piece.kind() == StandardPieceKind::Pawn && piece.color() == color).then_some(source)
+}
+
+/// Returns true if a piece of kind `kind` standing on `from` pseudo-legally
+/// attacks `to`, i.e. ignoring whether moving there would leave its own king
+/// in check.
+fn attacks(board: &StandardBoard, from: StandardIndex, kind: StandardPieceKind, to: StandardIndex) -> bool {
+    let df = to.file() as i8 - from.file() as i8;
+    let dr = to.rank() as i8 - from.rank() as i8;
+
+    match kind {
+        StandardPieceKind::Pawn => false,
+        StandardPieceKind::Knight => matches!((df.abs(), dr.abs()), (1, 2) | (2, 1)),
+        StandardPieceKind::King => (df, dr) != (0, 0) && df.abs() <= 1 && dr.abs() <= 1,
+        StandardPieceKind::Bishop => df.abs() == dr.abs() && df != 0 && path_is_clear(board, from, to),
+        StandardPieceKind::Rook => {
+            (df == 0) != (dr == 0) && path_is_clear(board, from, to)
+        }
+        StandardPieceKind::Queen => {
+            (df, dr) != (0, 0)
+                && (df == 0 || dr == 0 || df.abs() == dr.abs())
+                && path_is_clear(board, from, to)
+        }
+    }
+}
+
+/// Returns true if every square strictly between `from` and `to` is empty,
+/// assuming the two squares lie on a shared rank, file, or diagonal.
+fn path_is_clear(board: &StandardBoard, from: StandardIndex, to: StandardIndex) -> bool {
+    let step_file = (to.file() as i8 - from.file() as i8).signum();
+    let step_rank = (to.rank() as i8 - from.rank() as i8).signum();
+
+    let mut file = from.file() as i8 + step_file;
+    let mut rank = from.rank() as i8 + step_rank;
+
+    while (file, rank) != (to.file() as i8, to.rank() as i8) {
+        let square = StandardIndex::from_file_rank(file as u8, rank as u8)
+            .expect("a clear path between two squares on the board never leaves the board");
+        if board.get_piece_at(square).is_some() {
+            return false;
+        }
+        file += step_file;
+        rank += step_rank;
+    }
+
+    true
+}
+
+/// Returns true if `source` satisfies the given disambiguation field, or if
+/// there is no field to satisfy.
+fn matches_disambiguation(source: StandardIndex, field: Option<&DisambiguationField>) -> bool {
+    match field {
+        None => true,
+        Some(DisambiguationField::FileLetter(file)) => source.file() == file_to_index(*file),
+        Some(DisambiguationField::RankDigit(rank)) => source.rank() == rank_to_index(*rank),
+        Some(DisambiguationField::SourceSquare((file, rank))) => {
+            source.file() == file_to_index(*file) && source.rank() == rank_to_index(*rank)
+        }
+    }
+}
+
+/// Converts a parsed `(file, rank)` character pair into a [`StandardIndex`].
+fn square_from_chars(square: (char, char)) -> Option<StandardIndex> {
+    StandardIndex::try_from(square).ok()
+}
+
+/// Converts a SAN file character (`a`-`h`) into a 0-indexed file.
+fn file_to_index(file: char) -> u8 {
+    file as u8 - b'a'
+}
+
+/// Converts a SAN rank character (`1`-`8`) into a 0-indexed rank.
+fn rank_to_index(rank: char) -> u8 {
+    rank as u8 - b'1'
+}
+
 /// The distinct kinds of data conveyed by a SAN literal.
 ///
 /// Keep in mind that a SAN literal conveys information about
 /// a move which may or may not be valid in the context of a
 /// given board position. This struct stores the exact data
 /// conveyed by the literal, but needs a [`Board`](crate::core::Board) to be converted
-/// into a [`Move`](crate::core::Move), and a [`Validate`](crate::core::Validate) to be converted into a
-/// [`LegalMove`](crate::core::LegalMove).
+/// into a [`Move`](crate::core::Move) via [`San::into_move`], and a
+/// [`Validate`](crate::core::Validate) to be converted into a
+/// [`LegalMove`](crate::core::LegalMove) via [`San::into_legal_move`].
 #[derive(Debug, Eq, PartialEq)]
 enum SanData {
     AbbreviatedPawnMove(AbbreviatedPawnMove),
@@ -162,7 +674,7 @@ struct NormalMove {
 struct PawnMove {
     target: (char, char),
     is_capture: bool,
-    capture_rank: Option<char>,
+    capture_file: Option<char>,
     promotion_piece: Option<StandardPieceKind>,
 }
 
@@ -348,7 +860,7 @@ fn pawn_move<'a>(source: &'a str) -> SanResult<SanData> {
                 SanData::PawnMove(PawnMove {
                     target,
                     is_capture: file_capture_block.is_some(),
-                    capture_rank: file_capture_block.map(|(file, _)| file),
+                    capture_file: file_capture_block.map(|(file, _)| file),
                     promotion_piece: promotion,
                 }),
             )
@@ -413,6 +925,83 @@ fn san_literal<'a>(source: &'a str) -> SanResult<San> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::fen::Fen;
+
+    const STARTPOS: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    #[test]
+    fn resolves_pawn_double_push() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        let san = San::try_from("e4").unwrap();
+        let mv = san.into_move(&board).unwrap();
+        assert_eq!(mv.kind(), MoveKind::DoublePawnPush);
+    }
+
+    #[test]
+    fn resolves_disambiguated_knight_move() {
+        let board = Fen::try_from("4k3/8/8/8/8/8/8/N3K2N w - - 0 1").unwrap().board;
+        let san = San::try_from("Nab3").unwrap();
+        let mv = san.into_move(&board).unwrap();
+        assert_eq!(mv.source(), StandardIndex::from_file_rank(0, 0).unwrap());
+        assert_eq!(mv.target(), StandardIndex::from_file_rank(1, 2).unwrap());
+    }
+
+    #[test]
+    fn ambiguous_move_without_disambiguation_fails() {
+        let board = Fen::try_from("4k3/8/8/8/8/8/8/N1N1K3 w - - 0 1").unwrap().board;
+        let san = San::try_from("Nb3").unwrap();
+        san.into_move(&board)
+            .expect_err("two knights can reach b3, so this should be ambiguous");
+    }
+
+    #[test]
+    fn resolves_castle_into_legal_move() {
+        let board = Fen::try_from("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap().board;
+        let san = San::try_from("O-O").unwrap();
+        let mv = san.into_legal_move(&board).unwrap();
+        assert_eq!(mv.kind(), MoveKind::Castle { side: CastleSide::KingSide });
+    }
+
+    #[test]
+    fn serializes_pawn_push() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        let mv = board.validate(StandardMove::double_pawn_push(
+            StandardIndex::from_file_rank(4, 1).unwrap(),
+            StandardIndex::from_file_rank(4, 3).unwrap(),
+        )).unwrap();
+        assert_eq!(board.to_san(mv), "e4");
+    }
+
+    #[test]
+    fn serializes_disambiguated_knight_move() {
+        let board = Fen::try_from("4k3/8/8/8/8/8/8/N1N1K3 w - - 0 1").unwrap().board;
+        let mv = board.validate(StandardMove::quiet(
+            StandardIndex::from_file_rank(0, 0).unwrap(),
+            StandardIndex::from_file_rank(1, 2).unwrap(),
+        )).unwrap();
+        assert_eq!(board.to_san(mv), "Nab3");
+    }
+
+    #[test]
+    fn serializes_castle() {
+        let board = Fen::try_from("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap().board;
+        let mv = board.validate(StandardMove::castle(
+            StandardIndex::from_file_rank(4, 0).unwrap(),
+            StandardIndex::from_file_rank(6, 0).unwrap(),
+            CastleSide::KingSide,
+        )).unwrap();
+        assert_eq!(board.to_san(mv), "O-O");
+    }
+
+    #[test]
+    fn serializes_checkmate() {
+        let board = Fen::try_from("6k1/5ppp/8/8/8/8/8/R7 w - - 0 1").unwrap().board;
+        let mv = board.validate(StandardMove::quiet(
+            StandardIndex::from_file_rank(0, 0).unwrap(),
+            StandardIndex::from_file_rank(0, 7).unwrap(),
+        )).unwrap();
+        assert_eq!(board.to_san(mv), "Ra8#");
+    }
 
     #[test]
     fn basic_san_parsing() {
@@ -0,0 +1,283 @@
+//! UCI long algebraic move notation, e.g. `e2e4`, `e7e8q`, `e1g1`.
+//!
+//! Unlike SAN, a UCI literal is never ambiguous about which piece is
+//! moving: it simply names a source square and a target square. What it
+//! *doesn't* encode unambiguously is the board's interpretation of that
+//! pair of squares, since castling can be written either as the king
+//! moving two squares or as the king moving onto its own rook, depending
+//! on the engine/GUI on the other end. Resolving a [`UciMove`] therefore
+//! still needs a [`Board`] in the same way that resolving a
+//! [`San`](crate::io::san::San) does. Going the other way, rendering a
+//! [`LegalStandardMove`] as a literal via `Display` needs no board at all,
+//! since castling is always written with the king-moves-two-squares
+//! convention.
+
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::core::board::Board;
+use crate::standard::board::StandardBoard;
+use crate::standard::index::StandardIndex;
+use crate::standard::piece::StandardPieceKind;
+use crate::standard::r#move::{CastleSide, LegalStandardMove, MoveKind, StandardMove};
+
+/// The error returned when attempting to parse an invalid UCI move literal.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    /// Returned if the literal is not 4 or 5 ASCII characters long.
+    #[error("expected a 4 or 5 character ASCII literal; got {0} characters")]
+    InvalidLiteralLength(usize),
+    /// Returned if the leading source square is invalid.
+    #[error("expected a valid source square; got {0:?}")]
+    InvalidSourceSquare(String),
+    /// Returned if the target square is invalid.
+    #[error("expected a valid target square; got {0:?}")]
+    InvalidTargetSquare(String),
+    /// Returned if the trailing promotion character is invalid.
+    #[error("expected one of 'q', 'r', 'b', 'n'; got {0}")]
+    InvalidPromotionPiece(char),
+}
+
+/// The error returned when a [`UciMove`] cannot be resolved against a board.
+#[derive(Debug, Error)]
+pub enum UciResolutionError {
+    /// Returned when the source square of a [`UciMove`] is empty.
+    #[error("no piece to move at source square {0:?}")]
+    EmptySource(StandardIndex),
+}
+
+/// Represents the data derived from parsing a valid UCI move literal.
+///
+/// Parsing is provided via the `TryFrom<&str>` impl. Like
+/// [`San`](crate::io::san::San), a `UciMove` stores exactly what the
+/// literal conveys, and needs a [`Board`] to be resolved into a
+/// [`Move`](crate::core::r#move::Move) via [`UciMove::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UciMove {
+    source: StandardIndex,
+    target: StandardIndex,
+    promotion: Option<StandardPieceKind>,
+}
+
+impl<'a> TryFrom<&'a str> for UciMove {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        if !value.is_ascii() || (value.len() != 4 && value.len() != 5) {
+            return Err(ParseError::InvalidLiteralLength(value.chars().count()));
+        }
+
+        let source = square(&value[0..2])
+            .ok_or_else(|| ParseError::InvalidSourceSquare(value[0..2].to_owned()))?;
+        let target = square(&value[2..4])
+            .ok_or_else(|| ParseError::InvalidTargetSquare(value[2..4].to_owned()))?;
+
+        let promotion = match value.as_bytes().get(4) {
+            None => None,
+            Some(b'q') => Some(StandardPieceKind::Queen),
+            Some(b'r') => Some(StandardPieceKind::Rook),
+            Some(b'b') => Some(StandardPieceKind::Bishop),
+            Some(b'n') => Some(StandardPieceKind::Knight),
+            Some(&byte) => return Err(ParseError::InvalidPromotionPiece(byte as char)),
+        };
+
+        Ok(Self {
+            source,
+            target,
+            promotion,
+        })
+    }
+}
+
+impl UciMove {
+    /// Resolves this `UciMove` into a [`StandardMove`] by consulting `board`
+    /// for the pieces on the source and target squares and for the en passant
+    /// target, which is enough to tell the two castling conventions apart, to
+    /// normalise a castling target square to the one this crate's
+    /// [`Validate`](crate::core::board::Validate) implementation expects, and
+    /// to pick the move's [`MoveKind`].
+    pub fn resolve(self, board: &StandardBoard) -> Result<StandardMove, UciResolutionError> {
+        let piece = board
+            .get_piece_at(self.source)
+            .ok_or(UciResolutionError::EmptySource(self.source))?;
+
+        if piece.kind() == StandardPieceKind::King && self.is_castle(board) {
+            return Ok(self.resolve_castle());
+        }
+
+        let is_en_passant = piece.kind() == StandardPieceKind::Pawn
+            && board.get_piece_at(self.target).is_none()
+            && board.en_passant_target() == Some(self.target);
+        let is_capture = is_en_passant || board.get_piece_at(self.target).is_some();
+        let is_double_push = piece.kind() == StandardPieceKind::Pawn
+            && self.target.rank().abs_diff(self.source.rank()) == 2;
+
+        Ok(match self.promotion {
+            Some(promotion) => {
+                StandardMove::promotion(self.source, self.target, promotion, is_capture)
+            }
+            None if is_en_passant => StandardMove::en_passant(self.source, self.target),
+            None if is_capture => StandardMove::capture(self.source, self.target),
+            None if is_double_push => StandardMove::double_pawn_push(self.source, self.target),
+            None => StandardMove::quiet(self.source, self.target),
+        })
+    }
+
+    /// Returns true if `self` denotes a castling move, whether written as
+    /// the king moving two squares or as the king moving onto its own rook.
+    fn is_castle(&self, board: &StandardBoard) -> bool {
+        if self.source.rank() != self.target.rank() {
+            return false;
+        }
+
+        let file_delta = (self.target.file() as i8 - self.source.file() as i8).abs();
+        let color = board.get_piece_at(self.source).map(|king| king.color());
+        file_delta == 2
+            || board.get_piece_at(self.target).is_some_and(|rook| {
+                rook.kind() == StandardPieceKind::Rook && Some(rook.color()) == color
+            })
+    }
+
+    /// Normalises a castling literal, in either convention, to the
+    /// king-moves-two-squares target square this crate expects.
+    fn resolve_castle(self) -> StandardMove {
+        let rank = self.source.rank();
+        let kingside = self.target.file() > self.source.file();
+        let side = if kingside {
+            CastleSide::KingSide
+        } else {
+            CastleSide::QueenSide
+        };
+        let target_file = if kingside { 6 } else { 2 };
+        let target = StandardIndex::from_file_rank(target_file, rank)
+            .expect("the king-side and queen-side castle targets are always on the board");
+
+        StandardMove::castle(self.source, target, side)
+    }
+}
+
+/// Parses a two-character algebraic square such as `e4`.
+fn square(literal: &str) -> Option<StandardIndex> {
+    let mut chars = literal.chars();
+    let file = chars.next()?;
+    let rank = chars.next()?;
+    StandardIndex::try_from((file, rank)).ok()
+}
+
+impl From<LegalStandardMove> for UciMove {
+    /// Converts a legal move into its UCI literal representation, always
+    /// using the king-moves-two-squares convention for castling.
+    fn from(mv: LegalStandardMove) -> Self {
+        let promotion = match mv.kind() {
+            MoveKind::Promotion { piece, .. } => Some(piece),
+            _ => None,
+        };
+
+        Self {
+            source: mv.source(),
+            target: mv.target(),
+            promotion,
+        }
+    }
+}
+
+impl fmt::Display for UciMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_square(f, self.source)?;
+        write_square(f, self.target)?;
+        if let Some(promotion) = self.promotion {
+            write!(f, "{}", promotion_char(promotion))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a square as its two-character algebraic literal, e.g. `e4`.
+fn write_square(f: &mut fmt::Formatter<'_>, square: StandardIndex) -> fmt::Result {
+    write!(
+        f,
+        "{}{}",
+        (b'a' + square.file()) as char,
+        (b'1' + square.rank()) as char
+    )
+}
+
+/// Returns the lowercase UCI promotion character for `kind`.
+fn promotion_char(kind: StandardPieceKind) -> char {
+    match kind {
+        StandardPieceKind::Queen => 'q',
+        StandardPieceKind::Rook => 'r',
+        StandardPieceKind::Bishop => 'b',
+        StandardPieceKind::Knight => 'n',
+        _ => unreachable!("only queen, rook, bishop and knight promotions are representable"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::fen::Fen;
+
+    const STARTPOS: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    #[test]
+    fn parses_plain_move() {
+        let mv = UciMove::try_from("e2e4").unwrap();
+        assert_eq!(mv.source, square("e2").unwrap());
+        assert_eq!(mv.target, square("e4").unwrap());
+        assert_eq!(mv.promotion, None);
+    }
+
+    #[test]
+    fn parses_promotion_move() {
+        let mv = UciMove::try_from("e7e8q").unwrap();
+        assert_eq!(mv.promotion, Some(StandardPieceKind::Queen));
+    }
+
+    #[test]
+    fn rejects_malformed_literals() {
+        UciMove::try_from("e2e").expect_err("should fail on a short literal");
+        UciMove::try_from("e2e4qq").expect_err("should fail on a long literal");
+        UciMove::try_from("z2e4q").expect_err("should fail on an invalid square");
+        UciMove::try_from("e2e4z").expect_err("should fail on an invalid promotion piece");
+    }
+
+    #[test]
+    fn resolves_double_pawn_push() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        let mv = UciMove::try_from("e2e4").unwrap().resolve(&board).unwrap();
+        assert_eq!(mv.kind(), MoveKind::DoublePawnPush);
+    }
+
+    #[test]
+    fn resolves_kingside_castle_in_either_convention() {
+        let board = Fen::try_from("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap().board;
+
+        let king_two_squares = UciMove::try_from("e1g1").unwrap().resolve(&board).unwrap();
+        let king_onto_rook = UciMove::try_from("e1h1").unwrap().resolve(&board).unwrap();
+
+        assert_eq!(
+            king_two_squares.kind(),
+            MoveKind::Castle { side: CastleSide::KingSide }
+        );
+        assert_eq!(king_two_squares, king_onto_rook);
+    }
+
+    #[test]
+    fn resolve_fails_on_empty_source() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        UciMove::try_from("e4e5")
+            .unwrap()
+            .resolve(&board)
+            .expect_err("should fail since e4 is empty at the start position");
+    }
+
+    #[test]
+    fn displays_round_trip_through_parsing() {
+        for literal in ["e2e4", "e7e8q", "e1g1"] {
+            let mv = UciMove::try_from(literal).unwrap();
+            assert_eq!(mv.to_string(), literal);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{board::StandardBoard, index::StandardIndex};
+use super::{board::StandardBoard, index::StandardIndex, piece::StandardPieceKind};
 use crate::core::r#move::{IllegalMoveError, LegalMove, Move};
 use thiserror::Error;
 
@@ -22,12 +22,50 @@ impl IllegalMoveError for IllegalStandardMoveError {
     type LegalMove = LegalStandardMove;
 }
 
+/// Distinguishes the special cases a [`StandardMove`] can represent beyond
+/// a plain source/target pair, so that a move alone carries enough
+/// information to be processed and round-tripped to/from SAN and UCI
+/// without consulting the board again.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MoveKind {
+    /// A move to an empty square that is not a double pawn push.
+    Quiet,
+    /// A move that captures the piece standing on the target square.
+    Capture,
+    /// A pawn push of two squares from its starting rank.
+    DoublePawnPush,
+    /// A pawn capture of the pawn that just made a [`MoveKind::DoublePawnPush`].
+    EnPassant,
+    /// A castling move, which also relocates the castling rook.
+    Castle {
+        /// The side castled towards.
+        side: CastleSide,
+    },
+    /// A pawn push or capture onto the back rank.
+    Promotion {
+        /// The piece the pawn is promoted to.
+        piece: StandardPieceKind,
+        /// Whether this promotion also captures the piece on the target square.
+        is_capture: bool,
+    },
+}
+
+/// The side of the board a castling move castles towards.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CastleSide {
+    /// Kingside castling, i.e. `O-O`.
+    KingSide,
+    /// Queenside castling, i.e. `O-O-O`.
+    QueenSide,
+}
+
 /// Represents a possible move on a `StandardBoard`,
 /// including illegal moves.
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct StandardMove {
     source: StandardIndex,
     target: StandardIndex,
+    kind: MoveKind,
 }
 
 /// Represents a legal move on a `StandardBoard`.
@@ -43,11 +81,108 @@ impl LegalMove for LegalStandardMove {
     type Move = StandardMove;
 }
 
+/// Defaults to [`MoveKind::Quiet`], since a bare pair of squares carries no
+/// information about captures or special moves; use [`StandardMove::capture`]
+/// or one of the other constructors when that context is available.
 impl From<(StandardIndex, StandardIndex)> for StandardMove {
     fn from(value: (StandardIndex, StandardIndex)) -> Self {
         Self {
             source: value.0,
             target: value.1,
+            kind: MoveKind::Quiet,
+        }
+    }
+}
+
+impl StandardMove {
+    /// Returns the square this move departs from.
+    pub fn source(&self) -> StandardIndex {
+        self.source
+    }
+
+    /// Returns the square this move arrives at.
+    pub fn target(&self) -> StandardIndex {
+        self.target
+    }
+
+    /// Returns the kind of move this is.
+    pub fn kind(&self) -> MoveKind {
+        self.kind
+    }
+
+    /// Constructs a quiet (non-capturing, non-special) move.
+    pub fn quiet(source: StandardIndex, target: StandardIndex) -> Self {
+        Self {
+            source,
+            target,
+            kind: MoveKind::Quiet,
+        }
+    }
+
+    /// Constructs a move that captures the piece on `target`.
+    pub fn capture(source: StandardIndex, target: StandardIndex) -> Self {
+        Self {
+            source,
+            target,
+            kind: MoveKind::Capture,
+        }
+    }
+
+    /// Constructs a two-square pawn push from its starting rank.
+    pub fn double_pawn_push(source: StandardIndex, target: StandardIndex) -> Self {
+        Self {
+            source,
+            target,
+            kind: MoveKind::DoublePawnPush,
+        }
+    }
+
+    /// Constructs an en passant capture.
+    pub fn en_passant(source: StandardIndex, target: StandardIndex) -> Self {
+        Self {
+            source,
+            target,
+            kind: MoveKind::EnPassant,
+        }
+    }
+
+    /// Constructs a castling move towards `side`.
+    pub fn castle(source: StandardIndex, target: StandardIndex, side: CastleSide) -> Self {
+        Self {
+            source,
+            target,
+            kind: MoveKind::Castle { side },
         }
     }
+
+    /// Constructs a pawn promotion, optionally capturing the piece on `target`.
+    pub fn promotion(
+        source: StandardIndex,
+        target: StandardIndex,
+        piece: StandardPieceKind,
+        is_capture: bool,
+    ) -> Self {
+        Self {
+            source,
+            target,
+            kind: MoveKind::Promotion { piece, is_capture },
+        }
+    }
+}
+
+impl LegalStandardMove {
+    /// Returns the square this move departs from.
+    pub fn source(&self) -> StandardIndex {
+        self.0.source
+    }
+
+    /// Returns the square this move arrives at.
+    pub fn target(&self) -> StandardIndex {
+        self.0.target
+    }
+
+    /// Returns the kind of move this is.
+    pub fn kind(&self) -> MoveKind {
+        self.0.kind
+    }
 }
@@ -0,0 +1,375 @@
+//! [`GenerateMoves`] for [`StandardBoard`].
+
+use crate::core::board::{Board, Validate};
+use crate::core::movegen::GenerateMoves;
+
+use super::board::StandardBoard;
+use super::index::StandardIndex;
+use super::piece::{Color, StandardPieceKind};
+use super::r#move::{CastleSide, StandardMove};
+
+/// `(file, rank)` offsets a knight can leap to.
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+/// `(file, rank)` offsets a king can step to.
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// `(file, rank)` directions a bishop slides along.
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// `(file, rank)` directions a rook slides along.
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// `(file, rank)` directions a queen slides along.
+const QUEEN_DIRECTIONS: [(i8, i8); 8] = [
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+];
+
+impl GenerateMoves for StandardBoard {
+    fn legal_moves(&self) -> impl Iterator<Item = <Self as Validate>::LegalMove> {
+        self.pseudo_legal_moves()
+            .filter_map(|mv| self.validate(mv).ok())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn pseudo_legal_moves(&self) -> impl Iterator<Item = <Self as Validate>::Move> {
+        let color = self.side_to_move();
+        let mut moves = Vec::new();
+
+        for source in StandardIndex::iter() {
+            let Some(piece) = self.get_piece_at(source) else {
+                continue;
+            };
+            if piece.color() != color {
+                continue;
+            }
+
+            match piece.kind() {
+                StandardPieceKind::Pawn => push_pawn_moves(self, source, color, &mut moves),
+                StandardPieceKind::Knight => {
+                    push_leaper_moves(self, source, color, &KNIGHT_OFFSETS, &mut moves)
+                }
+                StandardPieceKind::King => {
+                    push_leaper_moves(self, source, color, &KING_OFFSETS, &mut moves);
+                    push_castle_moves(self, source, color, &mut moves);
+                }
+                StandardPieceKind::Bishop => {
+                    push_slider_moves(self, source, color, &BISHOP_DIRECTIONS, &mut moves)
+                }
+                StandardPieceKind::Rook => {
+                    push_slider_moves(self, source, color, &ROOK_DIRECTIONS, &mut moves)
+                }
+                StandardPieceKind::Queen => {
+                    push_slider_moves(self, source, color, &QUEEN_DIRECTIONS, &mut moves)
+                }
+            }
+        }
+
+        moves.into_iter()
+    }
+}
+
+/// Appends every pseudo-legal knight/king move from `source` to `moves`.
+fn push_leaper_moves(
+    board: &StandardBoard,
+    source: StandardIndex,
+    color: Color,
+    offsets: &[(i8, i8)],
+    moves: &mut Vec<StandardMove>,
+) {
+    for &(df, dr) in offsets {
+        let Some(target) = offset(source, df, dr) else {
+            continue;
+        };
+
+        match board.get_piece_at(target) {
+            None => moves.push(StandardMove::quiet(source, target)),
+            Some(occupant) if occupant.color() != color => {
+                moves.push(StandardMove::capture(source, target))
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+/// Appends every pseudo-legal sliding move from `source` to `moves`.
+fn push_slider_moves(
+    board: &StandardBoard,
+    source: StandardIndex,
+    color: Color,
+    directions: &[(i8, i8)],
+    moves: &mut Vec<StandardMove>,
+) {
+    for &(df, dr) in directions {
+        let mut target = source;
+        while let Some(next) = offset(target, df, dr) {
+            target = next;
+
+            match board.get_piece_at(target) {
+                None => moves.push(StandardMove::quiet(source, target)),
+                Some(occupant) if occupant.color() != color => {
+                    moves.push(StandardMove::capture(source, target));
+                    break;
+                }
+                Some(_) => break,
+            }
+        }
+    }
+}
+
+/// Appends every pseudo-legal push, double push, capture, en passant
+/// capture and promotion from the pawn on `source` to `moves`.
+fn push_pawn_moves(
+    board: &StandardBoard,
+    source: StandardIndex,
+    color: Color,
+    moves: &mut Vec<StandardMove>,
+) {
+    let direction: i8 = match color {
+        Color::White => 1,
+        Color::Black => -1,
+    };
+    let starting_rank: u8 = match color {
+        Color::White => 1,
+        Color::Black => 6,
+    };
+    let promotion_rank: u8 = match color {
+        Color::White => 7,
+        Color::Black => 0,
+    };
+    let promotion_pieces = [
+        StandardPieceKind::Queen,
+        StandardPieceKind::Rook,
+        StandardPieceKind::Bishop,
+        StandardPieceKind::Knight,
+    ];
+
+    if let Some(target) = offset(source, 0, direction) {
+        if board.get_piece_at(target).is_none() {
+            push_pawn_advance(source, target, promotion_rank, &promotion_pieces, false, moves);
+
+            if source.rank() == starting_rank {
+                if let Some(double_target) = offset(target, 0, direction) {
+                    if board.get_piece_at(double_target).is_none() {
+                        moves.push(StandardMove::double_pawn_push(source, double_target));
+                    }
+                }
+            }
+        }
+    }
+
+    for &df in &[-1, 1] {
+        let Some(target) = offset(source, df, direction) else {
+            continue;
+        };
+
+        if board
+            .get_piece_at(target)
+            .is_some_and(|occupant| occupant.color() != color)
+        {
+            push_pawn_advance(source, target, promotion_rank, &promotion_pieces, true, moves);
+        } else if board.en_passant_target() == Some(target) {
+            moves.push(StandardMove::en_passant(source, target));
+        }
+    }
+}
+
+/// Appends either a plain push/capture or every promotion variant of it,
+/// depending on whether `target` sits on the back rank.
+fn push_pawn_advance(
+    source: StandardIndex,
+    target: StandardIndex,
+    promotion_rank: u8,
+    promotion_pieces: &[StandardPieceKind],
+    is_capture: bool,
+    moves: &mut Vec<StandardMove>,
+) {
+    if target.rank() == promotion_rank {
+        for &piece in promotion_pieces {
+            moves.push(StandardMove::promotion(source, target, piece, is_capture));
+        }
+    } else if is_capture {
+        moves.push(StandardMove::capture(source, target));
+    } else {
+        moves.push(StandardMove::quiet(source, target));
+    }
+}
+
+/// Appends the kingside/queenside castling moves available to the king on
+/// `source`, provided the castling right is held, the path is clear, the
+/// king is not currently in check, and the king's transit square is not
+/// attacked. [`Validate`] only rejects moves that leave the king in check
+/// in the *resulting* position, so castling through or out of check must
+/// be ruled out here instead.
+fn push_castle_moves(
+    board: &StandardBoard,
+    source: StandardIndex,
+    color: Color,
+    moves: &mut Vec<StandardMove>,
+) {
+    let rank = source.rank();
+    let opponent = color.opposite();
+
+    if is_square_attacked(board, source, opponent) {
+        return;
+    }
+
+    for side in [CastleSide::KingSide, CastleSide::QueenSide] {
+        if !board.can_castle(color, side) {
+            continue;
+        }
+
+        let (path_files, transit_file, target_file): (&[u8], u8, u8) = match side {
+            CastleSide::KingSide => (&[5, 6], 5, 6),
+            CastleSide::QueenSide => (&[1, 2, 3], 3, 2),
+        };
+
+        let path_clear = path_files.iter().all(|&file| {
+            StandardIndex::from_file_rank(file, rank)
+                .is_some_and(|square| board.get_piece_at(square).is_none())
+        });
+
+        let transit_safe = StandardIndex::from_file_rank(transit_file, rank)
+            .is_some_and(|square| !is_square_attacked(board, square, opponent));
+
+        if let (true, true, Some(target)) = (
+            path_clear,
+            transit_safe,
+            StandardIndex::from_file_rank(target_file, rank),
+        ) {
+            moves.push(StandardMove::castle(source, target, side));
+        }
+    }
+}
+
+/// Returns true if any piece of `attacker` pseudo-legally attacks `square`.
+fn is_square_attacked(board: &StandardBoard, square: StandardIndex, attacker: Color) -> bool {
+    StandardIndex::iter().any(|source| {
+        board.get_piece_at(source).is_some_and(|piece| {
+            piece.color() == attacker && attacks_square(board, source, piece.kind(), square)
+        })
+    })
+}
+
+/// Returns true if a piece of kind `kind` standing on `source` pseudo-legally
+/// attacks `square`, i.e. ignoring whether moving there would leave its own
+/// king in check.
+fn attacks_square(
+    board: &StandardBoard,
+    source: StandardIndex,
+    kind: StandardPieceKind,
+    square: StandardIndex,
+) -> bool {
+    let df = square.file() as i8 - source.file() as i8;
+    let dr = square.rank() as i8 - source.rank() as i8;
+
+    match kind {
+        StandardPieceKind::Pawn => {
+            let direction: i8 = match board.get_piece_at(source).map(|p| p.color()) {
+                Some(Color::White) => 1,
+                _ => -1,
+            };
+            df.abs() == 1 && dr == direction
+        }
+        StandardPieceKind::Knight => matches!((df.abs(), dr.abs()), (1, 2) | (2, 1)),
+        StandardPieceKind::King => (df, dr) != (0, 0) && df.abs() <= 1 && dr.abs() <= 1,
+        StandardPieceKind::Bishop => {
+            df.abs() == dr.abs() && df != 0 && slider_path_clear(board, source, square)
+        }
+        StandardPieceKind::Rook => (df == 0) != (dr == 0) && slider_path_clear(board, source, square),
+        StandardPieceKind::Queen => {
+            (df, dr) != (0, 0)
+                && (df == 0 || dr == 0 || df.abs() == dr.abs())
+                && slider_path_clear(board, source, square)
+        }
+    }
+}
+
+/// Returns true if every square strictly between `from` and `to` is empty,
+/// assuming the two squares lie on a shared rank, file, or diagonal.
+fn slider_path_clear(board: &StandardBoard, from: StandardIndex, to: StandardIndex) -> bool {
+    let step_file = (to.file() as i8 - from.file() as i8).signum();
+    let step_rank = (to.rank() as i8 - from.rank() as i8).signum();
+
+    let mut file = from.file() as i8 + step_file;
+    let mut rank = from.rank() as i8 + step_rank;
+
+    while (file, rank) != (to.file() as i8, to.rank() as i8) {
+        let square = StandardIndex::from_file_rank(file as u8, rank as u8)
+            .expect("a clear path between two squares on the board never leaves the board");
+        if board.get_piece_at(square).is_some() {
+            return false;
+        }
+        file += step_file;
+        rank += step_rank;
+    }
+
+    true
+}
+
+/// Returns the square `(df, dr)` away from `square`, if it's on the board.
+fn offset(square: StandardIndex, df: i8, dr: i8) -> Option<StandardIndex> {
+    let file = u8::try_from(square.file() as i8 + df).ok()?;
+    let rank = u8::try_from(square.rank() as i8 + dr).ok()?;
+    StandardIndex::from_file_rank(file, rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::movegen::perft;
+    use crate::io::fen::Fen;
+
+    const STARTPOS: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    const KIWIPETE: &str =
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+    #[test]
+    fn perft_startpos_depth_1() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        assert_eq!(perft(&board, 1), 20);
+    }
+
+    #[test]
+    fn perft_startpos_depth_2() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        assert_eq!(perft(&board, 2), 400);
+    }
+
+    #[test]
+    fn perft_startpos_depth_3() {
+        let board = Fen::try_from(STARTPOS).unwrap().board;
+        assert_eq!(perft(&board, 3), 8902);
+    }
+
+    #[test]
+    fn perft_kiwipete_depth_1() {
+        let board = Fen::try_from(KIWIPETE).unwrap().board;
+        assert_eq!(perft(&board, 1), 48);
+    }
+}